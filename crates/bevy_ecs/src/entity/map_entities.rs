@@ -6,6 +6,9 @@ use std::fmt;
 #[derive(Debug)]
 pub enum MapEntitiesError {
     EntityNotFound(Entity),
+    /// Returned by [`EntityMap::get_inverse`] when more than one entity maps to the requested
+    /// entity, so the reverse lookup has no single correct answer.
+    AmbiguousInverse(Entity),
 }
 
 impl std::error::Error for MapEntitiesError {}
@@ -16,6 +19,12 @@ impl fmt::Display for MapEntitiesError {
             MapEntitiesError::EntityNotFound(_) => {
                 write!(f, "the given entity does not exist in the map")
             }
+            MapEntitiesError::AmbiguousInverse(_) => {
+                write!(
+                    f,
+                    "the given entity has more than one preimage in the map, so its inverse is ambiguous"
+                )
+            }
         }
     }
 }
@@ -50,6 +59,31 @@ impl fmt::Display for MapEntitiesError {
 /// }
 /// ```
 ///
+/// Most implementors of this trait can instead derive it, which generates the same kind
+/// of field-by-field remapping shown above:
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::MapEntities;
+/// use bevy_utils::HashMap;
+///
+/// #[derive(Component, MapEntities)]
+/// struct Spring {
+///     a: Entity,
+///     b: Entity,
+///     #[entities]
+///     anchors: Vec<Entity>,
+///     #[entities]
+///     connections: HashMap<u32, Entity>,
+///     #[map_entities(skip)]
+///     stiffness: f32,
+/// }
+/// ```
+///
+/// Plain [`Entity`] fields are remapped via [`EntityMapper::get`]. Fields annotated
+/// `#[entities]` must be a `Vec<Entity>`, `HashMap<_, Entity>`, or `Option<Entity>`, and
+/// are remapped element-wise. Fields annotated `#[map_entities(skip)]` are left untouched.
+///
 /// [`World`]: crate::world::World
 pub trait MapEntities {
     /// Updates all [`Entity`] references stored inside using `entity_map`.
@@ -65,11 +99,22 @@ pub trait MapEntities {
 ///
 /// This is typically used to coordinate data transfer between sets of entities, such as between a scene and the world or over the network.
 /// This is required as [`Entity`] identifiers are opaque; you cannot and do not want to reuse identifiers directly.
+///
+/// The mapping can be run in reverse via [`EntityMap::get_inverse`] and [`EntityMap::inverse`], which is useful when
+/// round-tripping entities back out of a world, such as when serializing a live world back to a scene.
 #[derive(Default, Debug)]
 pub struct EntityMap {
     map: HashMap<Entity, Entity>,
 }
 
+/// The preimage of a `to` entity under [`EntityMap::build_inverse`]: either exactly one `from`
+/// entity maps to it, or more than one does and the reverse mapping is ambiguous.
+#[derive(Debug, Clone, Copy)]
+enum Preimage {
+    Unique(Entity),
+    Ambiguous,
+}
+
 /// A wrapper for [`EntityMap`], augmenting it with the ability to allocate new [`Entity`] references in a destination
 /// world. These newly allocated references are guaranteed to never point to any living entity in that world.
 ///
@@ -83,6 +128,27 @@ pub struct EntityMapper<'m> {
     dead_start: Entity,
     /// The number of generations this mapper has allocated thus far.
     generations: u32,
+    /// The policy applied by [`EntityMapper::map`] when a lookup misses.
+    on_missing: MissingEntityPolicy,
+}
+
+/// Configures how [`EntityMapper::map`] behaves when asked to map an [`Entity`] that has no
+/// corresponding entry in the underlying [`EntityMap`].
+///
+/// This lets a single [`MapEntities`] implementation serve both strict callers (e.g. networking,
+/// where a missing reference indicates corrupt data) and lenient ones (e.g. scene loading, where
+/// references may legitimately point outside the loaded data) by configuring the [`EntityMapper`]
+/// they're given, rather than every implementor having to choose between [`EntityMapper::get`] and
+/// [`EntityMapper::get_or_alloc`] up front.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MissingEntityPolicy {
+    /// Fail with [`MapEntitiesError::EntityNotFound`]. This is the default.
+    #[default]
+    Error,
+    /// Allocate a new dead entity reference, as in [`EntityMapper::get_or_alloc`].
+    AllocDead,
+    /// Leave the original [`Entity`] id untouched.
+    Identity,
 }
 
 impl<'m> EntityMapper<'m> {
@@ -108,6 +174,24 @@ impl<'m> EntityMapper<'m> {
         new
     }
 
+    /// Returns the corresponding mapped entity, falling back to the configured
+    /// [`MissingEntityPolicy`] if `entity` has no entry in the underlying [`EntityMap`].
+    ///
+    /// The policy defaults to [`MissingEntityPolicy::Error`], matching [`EntityMapper::get`].
+    /// Use [`EntityMapper::set_on_missing`] to opt into a more lenient policy.
+    pub fn map(&mut self, entity: Entity) -> Result<Entity, MapEntitiesError> {
+        match self.on_missing {
+            MissingEntityPolicy::Error => self.get(entity),
+            MissingEntityPolicy::AllocDead => Ok(self.get_or_alloc(entity)),
+            MissingEntityPolicy::Identity => Ok(self.map.get(entity).unwrap_or(entity)),
+        }
+    }
+
+    /// Sets the policy applied by [`EntityMapper::map`] when a lookup misses.
+    pub fn set_on_missing(&mut self, policy: MissingEntityPolicy) {
+        self.on_missing = policy;
+    }
+
     /// Gets a reference to the underlying [`EntityMap`].
     pub fn get_map(&'m self) -> &'m EntityMap {
         self.map
@@ -124,6 +208,7 @@ impl<'m> EntityMapper<'m> {
             map,
             dead_start: world.spawn_empty().id(),
             generations: 0,
+            on_missing: MissingEntityPolicy::default(),
         }
     }
 
@@ -194,6 +279,53 @@ impl EntityMap {
         self.map.iter().map(|(from, to)| (*from, *to))
     }
 
+    /// Returns the inverse of this map, with every `(from, to)` pair exchanged for `(to, from)`.
+    ///
+    /// If this map is not injective (multiple `from` entities map to the same `to` entity), the
+    /// ambiguous `to` is omitted from the returned map entirely, rather than arbitrarily picking
+    /// one of its preimages. Use [`EntityMap::get_inverse`] to detect this ambiguity explicitly
+    /// via [`MapEntitiesError::AmbiguousInverse`].
+    pub fn inverse(&self) -> EntityMap {
+        let map = self
+            .build_inverse()
+            .into_iter()
+            .filter_map(|(to, preimage)| match preimage {
+                Preimage::Unique(from) => Some((to, from)),
+                Preimage::Ambiguous => None,
+            })
+            .collect();
+
+        EntityMap { map }
+    }
+
+    /// Returns the entity that maps to `entity` in this map, i.e. the `from` of the `(from, to)`
+    /// pair whose `to` is `entity`.
+    ///
+    /// The reverse mapping is recomputed on every call; callers that need repeated lookups should
+    /// build one with [`EntityMap::inverse`] and query that instead. Returns
+    /// [`MapEntitiesError::AmbiguousInverse`] if more than one entity maps to `entity`, since then
+    /// there is no single correct preimage to return.
+    pub fn get_inverse(&self, entity: Entity) -> Result<Entity, MapEntitiesError> {
+        match self.build_inverse().remove(&entity) {
+            Some(Preimage::Unique(from)) => Ok(from),
+            Some(Preimage::Ambiguous) => Err(MapEntitiesError::AmbiguousInverse(entity)),
+            None => Err(MapEntitiesError::EntityNotFound(entity)),
+        }
+    }
+
+    /// Builds a fresh reverse of `map`, flagging any `to` with more than one preimage as
+    /// [`Preimage::Ambiguous`] instead of silently keeping an arbitrary one.
+    fn build_inverse(&self) -> HashMap<Entity, Preimage> {
+        let mut inverse: HashMap<Entity, Preimage> = HashMap::default();
+        for (&from, &to) in self.map.iter() {
+            inverse
+                .entry(to)
+                .and_modify(|preimage| *preimage = Preimage::Ambiguous)
+                .or_insert(Preimage::Unique(from));
+        }
+        inverse
+    }
+
     /// Calls the provided closure with an [`EntityMapper`] created from this [`EntityMap`]. This allows the closure
     /// to allocate new entity references in the provided [`World`] that will never point at a living entity.
     pub fn with_mapper<R>(
@@ -207,3 +339,88 @@ impl EntityMap {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity {
+            generation: 0,
+            index,
+        }
+    }
+
+    #[test]
+    fn get_inverse_of_unique_mapping_returns_the_preimage() {
+        let mut map = EntityMap::default();
+        map.insert(entity(3), entity(200));
+
+        assert_eq!(map.get_inverse(entity(200)).unwrap(), entity(3));
+    }
+
+    #[test]
+    fn get_inverse_of_non_injective_mapping_is_ambiguous() {
+        let mut map = EntityMap::default();
+        map.insert(entity(1), entity(100));
+        map.insert(entity(2), entity(100));
+        map.insert(entity(3), entity(200));
+
+        match map.get_inverse(entity(100)) {
+            Err(MapEntitiesError::AmbiguousInverse(e)) => assert_eq!(e, entity(100)),
+            other => panic!("expected AmbiguousInverse, got {other:?}"),
+        }
+        // Unambiguous entries are unaffected by an unrelated collision elsewhere in the map.
+        assert_eq!(map.get_inverse(entity(200)).unwrap(), entity(3));
+    }
+
+    #[test]
+    fn inverse_omits_ambiguous_entries() {
+        let mut map = EntityMap::default();
+        map.insert(entity(1), entity(100));
+        map.insert(entity(2), entity(100));
+        map.insert(entity(3), entity(200));
+
+        let inverse = map.inverse();
+
+        assert_eq!(inverse.get(entity(200)).unwrap(), entity(3));
+        assert!(matches!(
+            inverse.get(entity(100)),
+            Err(MapEntitiesError::EntityNotFound(e)) if e == entity(100)
+        ));
+    }
+
+    #[test]
+    fn missing_entity_policy_error_returns_not_found() {
+        let mut map = EntityMap::default();
+        let mut world = World::new();
+        map.with_mapper(&mut world, |_, mapper| {
+            mapper.set_on_missing(MissingEntityPolicy::Error);
+            match mapper.map(entity(1)) {
+                Err(MapEntitiesError::EntityNotFound(e)) => assert_eq!(e, entity(1)),
+                other => panic!("expected EntityNotFound, got {other:?}"),
+            }
+        });
+    }
+
+    #[test]
+    fn missing_entity_policy_alloc_dead_behaves_like_get_or_alloc() {
+        let mut map = EntityMap::default();
+        let mut world = World::new();
+        map.with_mapper(&mut world, |_, mapper| {
+            mapper.set_on_missing(MissingEntityPolicy::AllocDead);
+            let mapped = mapper.map(entity(1)).unwrap();
+            assert_eq!(mapped, mapper.get_or_alloc(entity(1)));
+        });
+    }
+
+    #[test]
+    fn missing_entity_policy_identity_returns_original_entity() {
+        let mut map = EntityMap::default();
+        let mut world = World::new();
+        map.with_mapper(&mut world, |_, mapper| {
+            mapper.set_on_missing(MissingEntityPolicy::Identity);
+            assert_eq!(mapper.map(entity(1)).unwrap(), entity(1));
+        });
+    }
+}
@@ -0,0 +1,4 @@
+mod map_entities;
+
+pub use bevy_ecs_macros::MapEntities;
+pub use map_entities::*;
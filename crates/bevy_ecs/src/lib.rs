@@ -0,0 +1,3 @@
+//! Bevy's entity-component-system.
+
+pub mod entity;
@@ -0,0 +1,16 @@
+extern crate proc_macro;
+
+mod map_entities;
+
+use proc_macro::TokenStream;
+
+/// Implement the `MapEntities` trait for a struct, generating a `map_entities` body that
+/// remaps every `Entity` field it finds.
+///
+/// Plain `Entity` fields are remapped directly. Fields annotated `#[entities]` are treated
+/// as collections of entities (`Vec<Entity>`, `HashMap<_, Entity>`, or `Option<Entity>`) and
+/// are remapped element-wise. Fields annotated `#[map_entities(skip)]` are left untouched.
+#[proc_macro_derive(MapEntities, attributes(entities, map_entities))]
+pub fn derive_map_entities(input: TokenStream) -> TokenStream {
+    map_entities::derive_map_entities(input)
+}
@@ -0,0 +1,168 @@
+use bevy_macro_utils::BevyManifest;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Field, Index, Member};
+
+pub fn derive_map_entities(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    match derive_map_entities_inner(&ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.into_compile_error()),
+    }
+}
+
+fn derive_map_entities_inner(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ecs_path = bevy_ecs_path();
+
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "MapEntities can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut field_statements = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        if has_skip_meta(field) {
+            continue;
+        }
+
+        let member = field
+            .ident
+            .clone()
+            .map(Member::Named)
+            .unwrap_or_else(|| Member::Unnamed(Index::from(index)));
+
+        let statement = if field_has_attr(field, "entities") {
+            if is_map_type(&field.ty) {
+                quote! {
+                    for entity in self.#member.values_mut() {
+                        *entity = entity_mapper.get(*entity)?;
+                    }
+                }
+            } else {
+                quote! {
+                    for entity in std::iter::IntoIterator::into_iter(&mut self.#member) {
+                        *entity = entity_mapper.get(*entity)?;
+                    }
+                }
+            }
+        } else {
+            quote! {
+                self.#member = entity_mapper.get(self.#member)?;
+            }
+        };
+
+        field_statements.push(statement);
+    }
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ecs_path::entity::MapEntities for #struct_name #type_generics #where_clause {
+            fn map_entities(
+                &mut self,
+                entity_mapper: &mut #ecs_path::entity::EntityMapper,
+            ) -> Result<(), #ecs_path::entity::MapEntitiesError> {
+                #(#field_statements)*
+                Ok(())
+            }
+        }
+    })
+}
+
+/// Returns `true` if `ty`'s outermost type is a map (e.g. `HashMap`/`BTreeMap`), whose
+/// `&mut` iterator yields `(&K, &mut V)` pairs rather than `&mut V` directly.
+fn is_map_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "HashMap" || segment.ident == "BTreeMap")
+}
+
+fn field_has_attr(field: &Field, name: &str) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident(name))
+}
+
+fn has_skip_meta(field: &Field) -> bool {
+    let mut skip = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("map_entities") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+            }
+            Ok(())
+        });
+    }
+    skip
+}
+
+pub fn bevy_ecs_path() -> syn::Path {
+    BevyManifest::default().get_path("bevy_ecs")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_map_entities_inner;
+    use syn::{parse_quote, DeriveInput};
+
+    #[test]
+    fn plain_entity_field_is_remapped_directly() {
+        let ast: DeriveInput = parse_quote! {
+            struct Spring {
+                a: Entity,
+            }
+        };
+        let generated = derive_map_entities_inner(&ast).unwrap().to_string();
+        assert!(generated.contains("self . a = entity_mapper . get (self . a) ?"));
+    }
+
+    #[test]
+    fn hash_map_entities_field_iterates_values_mut() {
+        let ast: DeriveInput = parse_quote! {
+            struct Links {
+                #[entities]
+                connections: HashMap<Entity, Entity>,
+            }
+        };
+        let generated = derive_map_entities_inner(&ast).unwrap().to_string();
+        assert!(generated.contains("self . connections . values_mut ()"));
+        assert!(!generated.contains("IntoIterator"));
+    }
+
+    #[test]
+    fn vec_entities_field_iterates_into_iter_mut() {
+        let ast: DeriveInput = parse_quote! {
+            struct Links {
+                #[entities]
+                anchors: Vec<Entity>,
+            }
+        };
+        let generated = derive_map_entities_inner(&ast).unwrap().to_string();
+        assert!(generated.contains("IntoIterator :: into_iter (& mut self . anchors)"));
+    }
+
+    #[test]
+    fn skipped_field_is_left_untouched() {
+        let ast: DeriveInput = parse_quote! {
+            struct Spring {
+                #[map_entities(skip)]
+                stiffness: f32,
+            }
+        };
+        let generated = derive_map_entities_inner(&ast).unwrap().to_string();
+        assert!(!generated.contains("stiffness"));
+    }
+}